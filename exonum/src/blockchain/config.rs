@@ -605,13 +605,17 @@ impl GenesisConfigBuilder {
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
 
     use std::fmt::Display;
 
     use super::{
         ArtifactId, ConsensusConfig, GenesisConfigBuilder, PublicKey, ValidateInput, ValidatorKeys,
     };
-    use crate::crypto::{self, gen_keypair_from_seed, Seed, SEED_LENGTH};
+    use crate::{
+        crypto::{self, gen_keypair_from_seed, Seed, SEED_LENGTH},
+        merkledb::{BinaryValue, ObjectHash},
+    };
 
     fn assert_err_contains(actual: impl Display, expected: impl AsRef<str>) {
         let actual = actual.to_string();
@@ -663,6 +667,51 @@ mod tests {
         cfg.validate().expect("Expected valid consensus config");
     }
 
+    fn assert_round_trip(cfg: &ConsensusConfig) {
+        let bytes = cfg.to_bytes();
+        let restored = ConsensusConfig::from_bytes(bytes.clone().into()).unwrap();
+        assert_eq!(restored, *cfg);
+        assert_eq!(restored.to_bytes(), bytes);
+        assert_eq!(restored.object_hash(), cfg.object_hash());
+
+        let json = serde_json::to_string(cfg).unwrap();
+        let restored: ConsensusConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, *cfg);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+        assert_eq!(restored.object_hash(), cfg.object_hash());
+    }
+
+    #[test]
+    fn consensus_config_round_trip() {
+        assert_round_trip(&gen_consensus_config());
+    }
+
+    #[test]
+    fn consensus_config_round_trip_mini_fuzz() {
+        const SEED: u64 = 123_456;
+
+        let mut rng = StdRng::seed_from_u64(SEED);
+        for _ in 0..1_000 {
+            let validator_count = rng.gen_range(1..=16);
+            let min_propose_timeout = rng.gen_range(1..1_000);
+            let max_propose_timeout = rng.gen_range(min_propose_timeout..2_000);
+            let cfg = ConsensusConfig {
+                validator_keys: (0..validator_count).map(gen_validator_keys).collect(),
+                first_round_timeout: rng.gen_range(max_propose_timeout + 1..10_000),
+                status_timeout: rng.gen_range(1..10_000),
+                peers_timeout: rng.gen_range(1..100_000),
+                txs_block_limit: rng.gen_range(1..10_000),
+                max_message_len: rng.gen_range(ConsensusConfig::DEFAULT_MAX_MESSAGE_LEN..=u32::MAX),
+                min_propose_timeout,
+                max_propose_timeout,
+                propose_timeout_threshold: rng.gen(),
+            };
+            cfg.validate()
+                .expect("Generated consensus config is invalid");
+            assert_round_trip(&cfg);
+        }
+    }
+
     #[test]
     fn consensus_config_validate_err_round_trip() {
         let keys = gen_keys_pool(4);
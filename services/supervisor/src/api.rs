@@ -21,7 +21,9 @@
 //! - Public API:
 //!
 //!     - [Obtain consensus configuration](#obtain-consensus-configuration)
+//!     - [Obtain consensus configuration hash](#obtain-consensus-configuration-hash)
 //!     - [Obtain pending configuration proposal](#obtain-pending-configuration-proposal)
+//!     - [Obtain votes for pending configuration proposal](#obtain-votes-for-pending-configuration-proposal)
 //!     - [Obtain deployed artifacts and services](#obtain-deployed-artifacts-and-services)
 //!
 //! - Private API:
@@ -32,6 +34,7 @@
 //!     - [Vote for configuration proposal](#vote-for-configuration-proposal)
 //!     - [Obtain current configuration number](#obtain-current-configuration-number)
 //!     - [Obtain supervisor configuration](#obtain-supervisor-configuration)
+//!     - [Obtain supervisor information](#obtain-supervisor-information)
 //!     - [Check deployment status](#check-deployment-status)
 //!     - [Check migration status](#check-migration-status)
 //!
@@ -48,6 +51,10 @@
 //!
 //! Returns the current consensus configuration.
 //!
+//! A configuration proposal changing the consensus configuration is applied within the block
+//! at its `actual_from` height. Thus, the endpoint returns the new configuration as soon as
+//! this block is committed, and the previous one before that.
+//!
 //! [`ConsensusConfig`]: https://docs.rs/exonum/latest/exonum/blockchain/config/struct.ConsensusConfig.html
 //!
 //! ```
@@ -71,6 +78,46 @@
 //! # }
 //! ```
 //!
+//! ## Obtain Consensus Configuration Hash
+//!
+//! | Property    | Value |
+//! |-------------|-------|
+//! | Path        | `/api/services/supervisor/consensus-config-hash` |
+//! | Method      | GET   |
+//! | Query type  | - |
+//! | Return type | [`ConsensusConfigHashInfo`] |
+//!
+//! Returns the object hash of the current consensus configuration. This endpoint is
+//! a lightweight alternative to `consensus-config` for clients which only need to check
+//! whether their cached copy of the configuration is stale.
+//!
+//! The response does not include the height from which the configuration is actual,
+//! since the core schema does not store the activation height of the current config.
+//!
+//! [`ConsensusConfigHashInfo`]: struct.ConsensusConfigHashInfo.html
+//!
+//! ```
+//! use exonum::merkledb::ObjectHash;
+//! # use exonum_rust_runtime::ServiceFactory;
+//! use exonum_supervisor::api::ConsensusConfigHashInfo;
+//! # use exonum_supervisor::Supervisor;
+//! # use exonum_testkit::{ApiKind, TestKitBuilder};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let mut testkit = // Same as in previous example...
+//! #     TestKitBuilder::validator().with(Supervisor::simple()).build();
+//!
+//! let info: ConsensusConfigHashInfo = testkit
+//!     .api()
+//!     .public(ApiKind::Service("supervisor"))
+//!     .get("consensus-config-hash")
+//!     .await?;
+//! assert_eq!(info.hash, testkit.consensus_config().object_hash());
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Obtain Pending Configuration Proposal
 //!
 //! | Property    | Value |
@@ -107,6 +154,46 @@
 //! # }
 //! ```
 //!
+//! ## Obtain Votes for Pending Configuration Proposal
+//!
+//! | Property    | Value |
+//! |-------------|-------|
+//! | Path        | `/api/services/supervisor/config-votes` |
+//! | Method      | GET   |
+//! | Query type  | - |
+//! | Return type | `Option<[ConfigVotesInfo]>` |
+//!
+//! Returns the summary of votes for the configuration proposal which is currently pending:
+//! the number of validators, the number of votes cast and the number of votes required
+//! to apply the proposal in the current supervisor mode, as well as the validators
+//! which have not voted yet. If the proposal has collected enough votes, the response also
+//! contains the height at which it will be applied. Returns `None` if there is no pending
+//! configuration at the moment.
+//!
+//! [ConfigVotesInfo]: struct.ConfigVotesInfo.html
+//!
+//! ```
+//! # use exonum_rust_runtime::ServiceFactory;
+//! # use exonum_testkit::{ApiKind, TestKitBuilder};
+//! use exonum_supervisor::{api::ConfigVotesInfo, Supervisor};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let mut testkit = // Same as in previous example...
+//! #     TestKitBuilder::validator().with(Supervisor::simple()).build();
+//!
+//! let votes: Option<ConfigVotesInfo> = testkit
+//!     .api()
+//!     .public(ApiKind::Service("supervisor"))
+//!     .get("config-votes")
+//!     .await?;
+//!
+//! // Will be none, since we did not send a proposal.
+//! assert!(votes.is_none());
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Obtain Deployed Artifacts And Services
 //!
 //! | Property    | Value |
@@ -141,6 +228,10 @@
 //!
 //! # Private API
 //!
+//! Endpoints broadcasting transactions (deploy and migration requests, config proposals
+//! and votes) return a "Forbidden" error if the node is not an active validator,
+//! since transactions signed by such a node would be rejected by the supervisor.
+//!
 //! ## Request to Deploy an Artifact
 //!
 //! | Property    | Value |
@@ -350,7 +441,9 @@
 //! | Query type  | [`DeployRequest`] |
 //! | Return type | [`Hash`] |
 //!
-//! Votes for a pending configuration with a certain hash.
+//! Votes for a pending configuration with a certain hash. If there is no pending
+//! configuration with this hash, the endpoint returns a "Not Found" error and
+//! no transaction is broadcast.
 //!
 //! Depending on the supervisor operating mode, it may be required to vote by majority of
 //! nodes (in "decentralized" mode), or one vote will be enough (in "simple" mode).
@@ -492,6 +585,46 @@
 //! # }
 //! ```
 //!
+//! ## Obtain Supervisor Information
+//!
+//! | Property    | Value |
+//! |-------------|-------|
+//! | Path        | `/api/services/supervisor/supervisor-info` |
+//! | Method      | GET   |
+//! | Query type  | - |
+//! | Return type | [`SupervisorInfo`] |
+//!
+//! Returns the supervisor instance identifier, its artifact (including the version),
+//! the operating mode and whether the node can propose configuration changes, i.e.,
+//! whether the node is an active validator.
+//!
+//! [`SupervisorInfo`]: struct.SupervisorInfo.html
+//!
+//! ```
+//! use exonum::runtime::SUPERVISOR_INSTANCE_ID;
+//! use exonum_rust_runtime::ServiceFactory;
+//! use exonum_supervisor::{api::SupervisorInfo, mode::Mode, Supervisor};
+//! use exonum_testkit::{ApiKind, TestKitBuilder};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let mut testkit = // Same as in previous example...
+//! #     TestKitBuilder::validator().with(Supervisor::simple()).build();
+//!
+//! let info: SupervisorInfo = testkit
+//!     .api()
+//!     .private(ApiKind::Service("supervisor"))
+//!     .get("supervisor-info")
+//!     .await?;
+//!
+//! assert_eq!(info.instance_id, SUPERVISOR_INSTANCE_ID);
+//! assert_eq!(info.artifact, Supervisor.artifact_id());
+//! assert_eq!(info.mode, Mode::Simple);
+//! assert!(info.can_propose);
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Check Deployment Status
 //!
 //! | Property    | Value |
@@ -607,10 +740,10 @@
 
 use exonum::{
     blockchain::ConsensusConfig,
-    crypto::Hash,
+    crypto::{Hash, PublicKey},
     helpers::Height,
-    merkledb::AsReadonly,
-    runtime::{ArtifactId, DispatcherSchema, InstanceState},
+    merkledb::{access::Access, AsReadonly, ObjectHash},
+    runtime::{ArtifactId, DispatcherSchema, InstanceId, InstanceState},
 };
 use exonum_rust_runtime::{
     api::{self, ServiceApiBuilder, ServiceApiState},
@@ -618,11 +751,17 @@ use exonum_rust_runtime::{
 };
 use serde::{Deserialize, Serialize};
 
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fmt,
+    future::Future,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 use super::{
-    schema::SchemaImpl, transactions::SupervisorInterface, AsyncEventState, ConfigProposalWithHash,
-    ConfigPropose, ConfigVote, DeployRequest, MigrationRequest, MigrationState, SupervisorConfig,
+    mode::Mode, schema::SchemaImpl, transactions::SupervisorInterface, AsyncEventState,
+    ConfigProposalWithHash, ConfigPropose, ConfigVote, DeployRequest, MigrationRequest,
+    MigrationState, SupervisorConfig,
 };
 use exonum_proto::ProtobufBase64;
 
@@ -759,6 +898,137 @@ impl DispatcherInfo {
     }
 }
 
+/// Consensus configuration hash response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ConsensusConfigHashInfo {
+    /// Hash of the actual consensus configuration.
+    pub hash: Hash,
+}
+
+/// Summary of votes for the pending configuration proposal.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ConfigVotesInfo {
+    /// Hash of the pending proposal.
+    pub propose_hash: Hash,
+    /// Number of validators in the actual consensus configuration.
+    pub total_validators: usize,
+    /// Number of votes cast for the proposal.
+    pub votes_cast: usize,
+    /// Number of votes required to apply the proposal in the current supervisor mode.
+    pub votes_needed: usize,
+    /// Whether the proposal has enough votes to be applied.
+    pub enough_to_apply: bool,
+    /// Service keys of the validators which have not voted for the proposal yet.
+    pub not_voted: Vec<PublicKey>,
+    /// Height at which the proposal will be applied if it has enough votes,
+    /// or `None` if the proposal is still collecting votes.
+    pub will_activate_at_height: Option<Height>,
+}
+
+impl ConfigVotesInfo {
+    /// Loads votes information for the pending proposal from database.
+    fn load<T: Access>(
+        schema: &SchemaImpl<T>,
+        consensus_config: &ConsensusConfig,
+        height: Height,
+    ) -> Option<Self> {
+        let entry = schema.public.pending_proposal.get()?;
+        let total_validators = consensus_config.validator_keys.len();
+        let not_voted = consensus_config
+            .validator_keys
+            .iter()
+            .map(|keys| keys.service_key)
+            .filter(|key| {
+                !schema
+                    .config_confirms
+                    .confirmed_by(&entry.propose_hash, key)
+            })
+            .collect();
+        let votes_cast = schema.config_confirms.confirmations(&entry.propose_hash);
+        let votes_needed = schema
+            .supervisor_config()
+            .mode
+            .config_confirmations_needed(total_validators);
+        let enough_to_apply = votes_cast >= votes_needed;
+        let actual_from = entry.config_propose.actual_from;
+        let will_activate_at_height = if enough_to_apply && actual_from > height {
+            Some(actual_from)
+        } else {
+            None
+        };
+
+        Some(Self {
+            propose_hash: entry.propose_hash,
+            total_validators,
+            votes_cast,
+            votes_needed,
+            enough_to_apply,
+            not_voted,
+            will_activate_at_height,
+        })
+    }
+}
+
+/// Supervisor information response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SupervisorInfo {
+    /// Identifier of the supervisor service instance.
+    pub instance_id: InstanceId,
+    /// Artifact of the supervisor service, which includes its version.
+    pub artifact: ArtifactId,
+    /// Supervisor operating mode.
+    pub mode: Mode,
+    /// Whether the node can propose configuration changes, i.e., whether
+    /// the node is an active validator.
+    pub can_propose: bool,
+}
+
+/// Counter used to assign identifiers to supervisor API requests in logs.
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Calls the API handler, logging the request and its outcome under a unique request ID.
+/// For endpoints broadcasting transactions, the outcome is the transaction hash.
+async fn logged<Q, T, F, R>(
+    route: &'static str,
+    handler: F,
+    state: ServiceApiState,
+    query: Q,
+) -> Result<T, api::Error>
+where
+    Q: fmt::Debug + Send,
+    T: fmt::Debug,
+    F: FnOnce(ServiceApiState, Q) -> R + Send,
+    R: Future<Output = Result<T, api::Error>> + Send,
+{
+    let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+    log::trace!(
+        "Supervisor API request #{} to `{}` with {:?}",
+        request_id,
+        route,
+        query
+    );
+
+    let response = handler(state, query).await;
+    match &response {
+        Ok(value) => log::debug!(
+            "Supervisor API request #{} to `{}` succeeded: {:?}",
+            request_id,
+            route,
+            value
+        ),
+        Err(err) => log::debug!(
+            "Supervisor API request #{} to `{}` failed: {}",
+            request_id,
+            route,
+            err
+        ),
+    }
+    response
+}
+
 /// Public API specification of the supervisor service.
 struct PublicApi;
 
@@ -771,6 +1041,16 @@ impl PublicApi {
         Ok(state.data().for_core().consensus_config())
     }
 
+    /// Returns the hash of an actual consensus configuration of the blockchain.
+    async fn consensus_config_hash(
+        state: ServiceApiState,
+        _query: (),
+    ) -> Result<ConsensusConfigHashInfo, api::Error> {
+        Ok(ConsensusConfigHashInfo {
+            hash: state.data().for_core().consensus_config().object_hash(),
+        })
+    }
+
     /// Returns a pending propose config change.
     async fn config_proposal(
         state: ServiceApiState,
@@ -782,6 +1062,21 @@ impl PublicApi {
             .get())
     }
 
+    /// Returns a summary of votes for the pending config change.
+    async fn config_votes(
+        state: ServiceApiState,
+        _query: (),
+    ) -> Result<Option<ConfigVotesInfo>, api::Error> {
+        let core_schema = state.data().for_core();
+        let consensus_config = core_schema.consensus_config();
+        let schema = SchemaImpl::new(state.service_data());
+        Ok(ConfigVotesInfo::load(
+            &schema,
+            &consensus_config,
+            core_schema.height(),
+        ))
+    }
+
     /// Returns a list of deployed artifacts and initialized services.
     async fn services(state: ServiceApiState, _query: ()) -> Result<DispatcherInfo, api::Error> {
         Ok(DispatcherInfo::load(&state.data().for_dispatcher()))
@@ -794,9 +1089,9 @@ struct PrivateApi;
 impl PrivateApi {
     fn broadcaster(state: &ServiceApiState) -> Result<Broadcaster, api::Error> {
         state.broadcaster().ok_or_else(|| {
-            api::Error::bad_request()
+            api::Error::forbidden()
                 .title("Invalid broadcast request")
-                .detail("Nod is not a validator")
+                .detail("Node is not an active validator")
         })
     }
 
@@ -838,8 +1133,26 @@ impl PrivateApi {
 
     /// Creates and broadcasts the `ConfigVote` transaction, which is signed
     /// by the current node, and returns its hash.
+    ///
+    /// Returns an error if the vote does not refer to the pending proposal,
+    /// since the transaction would be rejected anyway.
     async fn confirm_config(state: ServiceApiState, vote: ConfigVote) -> Result<Hash, api::Error> {
-        Self::broadcaster(&state)?
+        let broadcaster = Self::broadcaster(&state)?;
+        let is_pending = SchemaImpl::new(state.service_data())
+            .public
+            .pending_proposal
+            .get()
+            .map_or(false, |entry| entry.propose_hash == vote.propose_hash);
+        if !is_pending {
+            return Err(api::Error::not_found()
+                .title("Unknown config proposal")
+                .detail(format!(
+                    "There is no pending config proposal with hash {}",
+                    vote.propose_hash
+                )));
+        }
+
+        broadcaster
             .confirm_config_change((), vote)
             .await
             .map_err(|err| api::Error::internal(err).title("Config vote failed"))
@@ -860,6 +1173,30 @@ impl PrivateApi {
         Ok(config)
     }
 
+    /// Returns information about the supervisor service and the node capabilities.
+    async fn supervisor_info(
+        state: ServiceApiState,
+        _query: (),
+    ) -> Result<SupervisorInfo, api::Error> {
+        let instance_id = state.instance().id;
+        let artifact = state
+            .data()
+            .for_dispatcher()
+            .get_instance(instance_id)
+            .ok_or_else(|| api::Error::internal("Supervisor instance is not found"))?
+            .spec
+            .artifact;
+
+        Ok(SupervisorInfo {
+            instance_id,
+            artifact,
+            mode: SchemaImpl::new(state.service_data())
+                .supervisor_config()
+                .mode,
+            can_propose: state.broadcaster().is_some(),
+        })
+    }
+
     /// Returns the state of deployment for the given deploy request.
     async fn deploy_status(
         state: ServiceApiState,
@@ -893,17 +1230,73 @@ impl PrivateApi {
 pub(crate) fn wire(builder: &mut ServiceApiBuilder) {
     builder
         .private_scope()
-        .pb_endpoint_mut("deploy-artifact", PrivateApi::deploy_artifact)
-        .pb_endpoint_mut("migrate", PrivateApi::migrate)
-        .pb_endpoint_mut("propose-config", PrivateApi::propose_config)
-        .pb_endpoint_mut("confirm-config", PrivateApi::confirm_config)
-        .endpoint("configuration-number", PrivateApi::configuration_number)
-        .endpoint("supervisor-config", PrivateApi::supervisor_config)
-        .endpoint("deploy-status", PrivateApi::deploy_status)
-        .endpoint("migration-status", PrivateApi::migration_status);
+        .pb_endpoint_mut("deploy-artifact", |state, query| {
+            logged("deploy-artifact", PrivateApi::deploy_artifact, state, query)
+        })
+        .pb_endpoint_mut("migrate", |state, query| {
+            logged("migrate", PrivateApi::migrate, state, query)
+        })
+        .pb_endpoint_mut("propose-config", |state, query| {
+            logged("propose-config", PrivateApi::propose_config, state, query)
+        })
+        .pb_endpoint_mut("confirm-config", |state, query| {
+            logged("confirm-config", PrivateApi::confirm_config, state, query)
+        })
+        .endpoint("configuration-number", |state, query| {
+            logged(
+                "configuration-number",
+                PrivateApi::configuration_number,
+                state,
+                query,
+            )
+        })
+        .endpoint("supervisor-config", |state, query| {
+            logged(
+                "supervisor-config",
+                PrivateApi::supervisor_config,
+                state,
+                query,
+            )
+        })
+        .endpoint("supervisor-info", |state, query| {
+            logged("supervisor-info", PrivateApi::supervisor_info, state, query)
+        })
+        .endpoint("deploy-status", |state, query| {
+            logged("deploy-status", PrivateApi::deploy_status, state, query)
+        })
+        .endpoint("migration-status", |state, query| {
+            logged(
+                "migration-status",
+                PrivateApi::migration_status,
+                state,
+                query,
+            )
+        });
     builder
         .public_scope()
-        .endpoint("consensus-config", PublicApi::consensus_config)
-        .endpoint("config-proposal", PublicApi::config_proposal)
-        .endpoint("services", PublicApi::services);
+        .endpoint("consensus-config", |state, query| {
+            logged(
+                "consensus-config",
+                PublicApi::consensus_config,
+                state,
+                query,
+            )
+        })
+        .endpoint("consensus-config-hash", |state, query| {
+            logged(
+                "consensus-config-hash",
+                PublicApi::consensus_config_hash,
+                state,
+                query,
+            )
+        })
+        .endpoint("config-proposal", |state, query| {
+            logged("config-proposal", PublicApi::config_proposal, state, query)
+        })
+        .endpoint("config-votes", |state, query| {
+            logged("config-votes", PublicApi::config_votes, state, query)
+        })
+        .endpoint("services", |state, query| {
+            logged("services", PublicApi::services, state, query)
+        });
 }
@@ -77,6 +77,8 @@ pub enum ConfigurationError {
     IncorrectConfigurationNumber = 52,
     /// Invalid configuration for supervisor.
     InvalidConfig = 53,
+    /// Proposed consensus configuration is equal to the actual one.
+    UnchangedConsensusConfig = 54,
 }
 
 impl ConfigurationError {
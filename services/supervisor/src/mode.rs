@@ -92,16 +92,17 @@ impl Mode {
         config_confirms: &MultisigIndex<T, Hash>,
         validators: usize,
     ) -> bool {
+        config_confirms.confirmations(config_hash) >= self.config_confirmations_needed(validators)
+    }
+
+    /// Returns the number of confirmations required to apply a config.
+    #[must_use]
+    pub fn config_confirmations_needed(&self, validators: usize) -> usize {
         match self {
-            Self::Simple => {
-                // For simple supervisor one confirmation (from us) is enough.
-                config_confirms.confirmations(config_hash) >= 1
-            }
-            Self::Decentralized => {
-                // Apply pending config if 2/3+1 validators voted for it.
-                let confirmations = config_confirms.confirmations(config_hash);
-                confirmations >= byzantine_quorum(validators)
-            }
+            // For simple supervisor one confirmation (from us) is enough.
+            Self::Simple => 1,
+            // Apply pending config if 2/3+1 validators voted for it.
+            Self::Decentralized => byzantine_quorum(validators),
         }
     }
 
@@ -712,6 +712,10 @@ impl Supervisor {
                     config
                         .validate()
                         .map_err(ConfigurationError::malformed_propose)?;
+
+                    if *config == context.data().for_core().consensus_config() {
+                        return Err(ConfigurationError::UnchangedConsensusConfig.into());
+                    }
                 }
 
                 ConfigChange::Service(config) => {
@@ -13,14 +13,21 @@
 // limitations under the License.
 
 use exonum::{
-    blockchain::ConsensusConfig, crypto::Hash, helpers::ValidatorId,
+    blockchain::ConsensusConfig,
+    crypto::Hash,
+    helpers::{Height, ValidatorId},
     runtime::SUPERVISOR_INSTANCE_ID,
 };
 use exonum_merkledb::ObjectHash;
-use exonum_testkit::{ApiKind, TestKit, TestKitApi};
+use exonum_rust_runtime::ServiceFactory;
+use exonum_testkit::{ApiKind, TestKit, TestKitApi, TestKitBuilder};
 
 use crate::utils::*;
-use exonum_supervisor::{ConfigProposalWithHash, ConfigPropose, ConfigVote, SupervisorInterface};
+use exonum_supervisor::{
+    api::{ConfigVotesInfo, ConsensusConfigHashInfo, SupervisorInfo},
+    mode::Mode,
+    ConfigProposalWithHash, ConfigPropose, ConfigVote, Supervisor, SupervisorInterface,
+};
 
 async fn actual_consensus_config(api: &TestKitApi) -> ConsensusConfig {
     api.public(ApiKind::Service("supervisor"))
@@ -29,6 +36,15 @@ async fn actual_consensus_config(api: &TestKitApi) -> ConsensusConfig {
         .unwrap()
 }
 
+async fn actual_consensus_config_hash(api: &TestKitApi) -> Hash {
+    let info: ConsensusConfigHashInfo = api
+        .public(ApiKind::Service("supervisor"))
+        .get("consensus-config-hash")
+        .await
+        .unwrap();
+    info.hash
+}
+
 async fn current_config_proposal(api: &TestKitApi) -> Option<ConfigProposalWithHash> {
     api.public(ApiKind::Service("supervisor"))
         .get("config-proposal")
@@ -36,6 +52,13 @@ async fn current_config_proposal(api: &TestKitApi) -> Option<ConfigProposalWithH
         .unwrap()
 }
 
+async fn config_votes(api: &TestKitApi) -> Option<ConfigVotesInfo> {
+    api.public(ApiKind::Service("supervisor"))
+        .get("config-votes")
+        .await
+        .unwrap()
+}
+
 pub async fn create_proposal(api: &TestKitApi, proposal: ConfigPropose) -> Hash {
     let hash: Hash = api
         .private(ApiKind::Service("supervisor"))
@@ -56,6 +79,13 @@ async fn confirm_config(api: &TestKitApi, confirm: ConfigVote) -> Hash {
     hash
 }
 
+async fn supervisor_info(api: &TestKitApi) -> SupervisorInfo {
+    api.private(ApiKind::Service("supervisor"))
+        .get("supervisor-info")
+        .await
+        .unwrap()
+}
+
 async fn configuration_number(api: &TestKitApi) -> u64 {
     api.private(ApiKind::Service("supervisor"))
         .get("configuration-number")
@@ -70,12 +100,101 @@ async fn test_consensus_config_api() {
     assert_eq!(testkit.consensus_config(), consensus_config);
 }
 
+/// Checks that the new consensus config is applied within the block at `actual_from`
+/// height, and thus is returned by the API once this block is committed.
+#[tokio::test]
+async fn test_consensus_config_api_at_activation_height() {
+    let mut testkit = testkit_with_supervisor(1);
+    let old_consensus_config = testkit.consensus_config();
+    let new_consensus_config = consensus_config_propose_first_variant(&testkit);
+    let actual_from = Height(5);
+    let config_proposal = ConfigProposeBuilder::new(actual_from)
+        .extend_consensus_config_propose(new_consensus_config.clone())
+        .build();
+
+    let hash = create_proposal(&testkit.api(), config_proposal).await;
+    let block = testkit.create_block();
+    block[hash].status().unwrap();
+
+    testkit.create_blocks_until(actual_from.previous());
+    let consensus_config = actual_consensus_config(&testkit.api()).await;
+    assert_eq!(consensus_config, old_consensus_config);
+
+    testkit.create_block();
+    assert_eq!(testkit.height(), actual_from);
+    let consensus_config = actual_consensus_config(&testkit.api()).await;
+    assert_eq!(consensus_config, new_consensus_config);
+
+    testkit.create_block();
+    assert_eq!(testkit.height(), actual_from.next());
+    let consensus_config = actual_consensus_config(&testkit.api()).await;
+    assert_eq!(consensus_config, new_consensus_config);
+}
+
+#[tokio::test]
+async fn test_consensus_config_hash_api() {
+    let mut testkit = testkit_with_supervisor(2);
+    let config_hash = actual_consensus_config_hash(&testkit.api()).await;
+    assert_eq!(config_hash, testkit.consensus_config().object_hash());
+
+    // The hash should change once a new config is applied.
+    apply_config(&mut testkit).await;
+    let new_config_hash = actual_consensus_config_hash(&testkit.api()).await;
+    assert_ne!(new_config_hash, config_hash);
+    assert_eq!(new_config_hash, testkit.consensus_config().object_hash());
+}
+
 #[tokio::test]
 async fn test_config_proposal_api() {
     let mut testkit = testkit_with_supervisor(1);
     assert_eq!(current_config_proposal(&testkit.api()).await, None);
 }
 
+#[tokio::test]
+async fn test_config_votes_api() {
+    let mut testkit = testkit_with_supervisor(4);
+    assert_eq!(config_votes(&testkit.api()).await, None);
+
+    let consensus_proposal = consensus_config_propose_first_variant(&testkit);
+    // Use a distant height, so that the proposal will not be applied during the test.
+    let config_proposal = ConfigProposeBuilder::new(Height(10))
+        .extend_consensus_config_propose(consensus_proposal)
+        .build();
+    let proposal_hash = config_proposal.object_hash();
+    let hash = create_proposal(&testkit.api(), config_proposal).await;
+    let block = testkit.create_block();
+    block[hash].status().unwrap();
+
+    // The author of the proposal is considered to have voted for it.
+    let votes = config_votes(&testkit.api())
+        .await
+        .expect("Config proposal was not registered.");
+    assert_eq!(votes.propose_hash, proposal_hash);
+    assert_eq!(votes.total_validators, 4);
+    assert_eq!(votes.votes_cast, 1);
+    assert_eq!(votes.votes_needed, 3);
+    assert!(!votes.enough_to_apply);
+    assert_eq!(votes.will_activate_at_height, None);
+    let service_keys: Vec<_> = testkit
+        .network()
+        .validators()
+        .iter()
+        .map(|validator| validator.service_keypair().public_key())
+        .collect();
+    assert_eq!(votes.not_voted, &service_keys[1..]);
+
+    let confirmations = build_confirmation_transactions(&testkit, proposal_hash, ValidatorId(0));
+    testkit.create_block_with_transactions(confirmations.into_iter().take(2));
+
+    let votes = config_votes(&testkit.api())
+        .await
+        .expect("Config proposal was not registered.");
+    assert_eq!(votes.votes_cast, 3);
+    assert!(votes.enough_to_apply);
+    assert_eq!(votes.will_activate_at_height, Some(Height(10)));
+    assert_eq!(votes.not_voted, &service_keys[3..]);
+}
+
 #[tokio::test]
 async fn test_confirm_proposal_with_api() {
     let mut testkit = testkit_with_supervisor(2);
@@ -114,6 +233,83 @@ async fn test_confirm_proposal_with_api() {
     assert_eq!(consensus_proposal, consensus_config);
 }
 
+/// Checks that `confirm-config` returns `NotFound` for votes referring to an unknown proposal.
+#[tokio::test]
+async fn test_confirm_unknown_proposal_with_api() {
+    let mut testkit = testkit_with_supervisor(2);
+    let vote = ConfigVote::new(Hash::zero());
+    let error = testkit
+        .api()
+        .private(ApiKind::Service("supervisor"))
+        .query(&vote)
+        .post::<Hash>("confirm-config")
+        .await
+        .expect_err("Call for `confirm-config` API endpoint succeed, but was expected to fail");
+
+    assert_eq!(u16::from(error.http_code), 404);
+    // No transaction should be broadcast.
+    let block = testkit.create_block();
+    assert!(block.transactions.is_empty());
+}
+
+/// Checks that `confirm-config` returns `Not Found` if the vote refers to a proposal
+/// other than the pending one.
+#[tokio::test]
+async fn test_confirm_mismatched_proposal_with_api() {
+    let mut testkit = testkit_with_supervisor(2);
+    let consensus_proposal = consensus_config_propose_first_variant(&testkit);
+    let config_proposal = ConfigProposeBuilder::new(CFG_CHANGE_HEIGHT)
+        .extend_consensus_config_propose(consensus_proposal)
+        .build();
+
+    testkit
+        .create_block_with_transaction(sign_config_propose_transaction(
+            &testkit,
+            config_proposal.clone(),
+            ValidatorId(1),
+        ))
+        .transactions[0]
+        .status()
+        .expect("Transaction with change propose discarded.");
+    let pending_config = current_config_proposal(&testkit.api())
+        .await
+        .expect("Config proposal was not registered.");
+    assert_eq!(pending_config.propose_hash, config_proposal.object_hash());
+
+    let vote = ConfigVote::new(Hash::zero());
+    let error = testkit
+        .api()
+        .private(ApiKind::Service("supervisor"))
+        .query(&vote)
+        .post::<Hash>("confirm-config")
+        .await
+        .expect_err("Call for `confirm-config` API endpoint succeed, but was expected to fail");
+
+    assert_eq!(u16::from(error.http_code), 404);
+    // No transaction should be broadcast.
+    let block = testkit.create_block();
+    assert!(block.transactions.is_empty());
+}
+
+/// Checks that `confirm-config` returns `Forbidden` if the node is not a validator.
+#[tokio::test]
+async fn test_confirm_proposal_from_auditor_with_api() {
+    let mut testkit = TestKitBuilder::auditor()
+        .with_validators(1)
+        .with(Supervisor::decentralized())
+        .build();
+    let vote = ConfigVote::new(Hash::zero());
+    let error = testkit
+        .api()
+        .private(ApiKind::Service("supervisor"))
+        .query(&vote)
+        .post::<Hash>("confirm-config")
+        .await
+        .expect_err("Call for `confirm-config` API endpoint succeed, but was expected to fail");
+
+    assert_eq!(u16::from(error.http_code), 403);
+}
+
 #[tokio::test]
 async fn test_send_proposal_with_api() {
     let mut testkit = testkit_with_supervisor(2);
@@ -203,3 +399,22 @@ async fn test_configuration_number() {
     let new_configuration_number = configuration_number(&testkit.api()).await;
     assert_eq!(new_configuration_number, 1);
 }
+
+/// Checks that supervisor information reflects whether the node can propose configs.
+#[tokio::test]
+async fn test_supervisor_info() {
+    let mut testkit = testkit_with_supervisor(2);
+    let info = supervisor_info(&testkit.api()).await;
+    assert_eq!(info.instance_id, SUPERVISOR_INSTANCE_ID);
+    assert_eq!(info.artifact, Supervisor.artifact_id());
+    assert_eq!(info.mode, Mode::Decentralized);
+    assert!(info.can_propose);
+
+    let mut testkit = TestKitBuilder::auditor()
+        .with_validators(1)
+        .with(Supervisor::decentralized())
+        .build();
+    let info = supervisor_info(&testkit.api()).await;
+    assert_eq!(info.mode, Mode::Decentralized);
+    assert!(!info.can_propose);
+}
@@ -12,11 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use exonum::{helpers::ValidatorId, merkledb::ObjectHash};
-use exonum_testkit::TestKitBuilder;
+use exonum::{
+    blockchain::ConsensusConfig,
+    helpers::ValidatorId,
+    merkledb::ObjectHash,
+    runtime::{ErrorMatch, SUPERVISOR_INSTANCE_ID},
+};
+use exonum_testkit::{TestKit, TestKitBuilder};
 
 use crate::utils::*;
-use exonum_supervisor::Supervisor;
+use exonum_supervisor::{ConfigurationError, Supervisor};
+
+/// Proposes the provided consensus config and checks that the proposal is rejected.
+fn assert_consensus_config_rejected(
+    testkit: &mut TestKit,
+    new_consensus_config: ConsensusConfig,
+    expected_description: &str,
+) {
+    let config_proposal = ConfigProposeBuilder::new(CFG_CHANGE_HEIGHT)
+        .extend_consensus_config_propose(new_consensus_config)
+        .build();
+    let signed_proposal = sign_config_propose_transaction(testkit, config_proposal, ValidatorId(0));
+    let block = testkit.create_block_with_transaction(signed_proposal);
+    let err = block.transactions[0].status().unwrap_err();
+    assert_eq!(
+        *err,
+        ErrorMatch::from_fail(&ConfigurationError::MalformedConfigPropose)
+            .for_service(SUPERVISOR_INSTANCE_ID)
+            .with_description_containing(expected_description)
+    );
+    assert_eq!(config_propose_entry(testkit), None);
+}
 
 #[test]
 fn test_add_nodes_to_validators() {
@@ -152,3 +178,55 @@ fn test_change_our_validator_id() {
     assert_eq!(&testkit.network().validators()[1], testkit.network().us());
     assert_eq!(testkit.consensus_config(), new_consensus_config);
 }
+
+#[test]
+fn test_duplicated_validator_keys() {
+    let mut testkit = testkit_with_supervisor(4);
+    let new_consensus_config = {
+        let mut cfg = testkit.consensus_config();
+        // Add one of the validators for the second time.
+        let keys = cfg.validator_keys[1];
+        cfg.validator_keys.push(keys);
+        cfg
+    };
+
+    assert_consensus_config_rejected(
+        &mut testkit,
+        new_consensus_config,
+        "Duplicated keys are found",
+    );
+}
+
+#[test]
+fn test_empty_validator_keys() {
+    let mut testkit = testkit_with_supervisor(4);
+    let new_consensus_config = {
+        let mut cfg = testkit.consensus_config();
+        cfg.validator_keys.clear();
+        cfg
+    };
+
+    assert_consensus_config_rejected(
+        &mut testkit,
+        new_consensus_config,
+        "must have at least one validator",
+    );
+}
+
+#[test]
+fn test_unchanged_consensus_config() {
+    let mut testkit = testkit_with_supervisor(4);
+    let config_proposal = ConfigProposeBuilder::new(CFG_CHANGE_HEIGHT)
+        .extend_consensus_config_propose(testkit.consensus_config())
+        .build();
+    let signed_proposal =
+        sign_config_propose_transaction(&testkit, config_proposal, ValidatorId(0));
+    let block = testkit.create_block_with_transaction(signed_proposal);
+    let err = block.transactions[0].status().unwrap_err();
+    assert_eq!(
+        *err,
+        ErrorMatch::from_fail(&ConfigurationError::UnchangedConsensusConfig)
+            .for_service(SUPERVISOR_INSTANCE_ID)
+    );
+    assert_eq!(config_propose_entry(&testkit), None);
+}